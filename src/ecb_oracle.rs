@@ -0,0 +1,106 @@
+//! The classic byte-at-a-time ECB decryption attack, which drives home why
+//! "ECB is not secure" from the top of the crate is more than a slogan.
+//!
+//! The attacker is handed an `oracle` that prepends their chosen bytes to a fixed
+//! unknown secret and returns `ecb_encrypt` of the whole thing under a key they
+//! never see. Because ECB encrypts every block independently and deterministically,
+//! the attacker can line the secret up one byte at a time against a block boundary
+//! and brute-force it, recovering the entire secret without ever learning the key.
+
+/// Recovers the secret appended by an ECB encryption oracle.
+///
+/// The oracle is any `Fn(&[u8]) -> Vec<u8>` that computes `ecb_encrypt(attacker ++ secret)`
+/// under a fixed key. The returned bytes are the recovered secret.
+pub fn crack_ecb_oracle(oracle: impl Fn(&[u8]) -> Vec<u8>) -> Vec<u8> {
+    let block_size = detect_block_size(&oracle);
+
+    // Confirm the oracle really is ECB: a long run of identical bytes must produce
+    // repeated ciphertext blocks, which `detect_ecb` flags.
+    let repeated = vec![b'A'; block_size * 4];
+    assert!(
+        crate::detect_ecb(&oracle(&repeated)),
+        "oracle does not appear to use ECB mode"
+    );
+
+    let secret_upper_bound = oracle(&[]).len();
+    let mut recovered: Vec<u8> = Vec::new();
+
+    for i in 0..=secret_upper_bound {
+        let block_index = i / block_size;
+        let block_start = block_index * block_size;
+        let block_end = block_start + block_size;
+
+        // A prefix one byte shorter than a block boundary pushes exactly one
+        // unknown secret byte to the end of the target block.
+        let prefix = vec![b'A'; block_size - 1 - (i % block_size)];
+
+        let target = oracle(&prefix);
+        if block_end > target.len() {
+            break;
+        }
+        let target_block = &target[block_start..block_end];
+
+        // Brute-force that final byte: the known prefix plus the already-recovered
+        // bytes plus each candidate fills the same block, so a matching ciphertext
+        // block reveals the byte.
+        let mut found = false;
+        for guess in 0u16..=255 {
+            let mut crafted = prefix.clone();
+            crafted.extend_from_slice(&recovered);
+            crafted.push(guess as u8);
+
+            let out = oracle(&crafted);
+            if out[block_start..block_end] == *target_block {
+                recovered.push(guess as u8);
+                found = true;
+                break;
+            }
+        }
+
+        // No match means we have walked into the oracle's own PKCS#7 padding, whose
+        // value shifts as our prefix length changes. The last "byte" we recovered
+        // was that padding (`0x01`), so drop it and stop.
+        if !found {
+            recovered.pop();
+            break;
+        }
+    }
+
+    recovered
+}
+
+/// Detects the cipher's block size by growing the attacker input until the
+/// ciphertext length jumps; the size of that jump is one block.
+fn detect_block_size(oracle: &impl Fn(&[u8]) -> Vec<u8>) -> usize {
+    let base_len = oracle(&[]).len();
+    for i in 1..=256 {
+        let len = oracle(&vec![b'A'; i]).len();
+        if len != base_len {
+            return len - base_len;
+        }
+    }
+    panic!("could not detect a block size from the oracle");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecb_encrypt;
+
+    const TEST_KEY: [u8; 16] = [
+        6, 108, 74, 203, 170, 212, 94, 238, 171, 104, 19, 17, 248, 197, 127, 138,
+    ];
+
+    #[test]
+    fn recovers_secret_via_oracle() {
+        let secret = b"Polkadot Blockchain Academy!".to_vec();
+        let secret_for_oracle = secret.clone();
+        let oracle = move |attacker: &[u8]| {
+            let mut data = attacker.to_vec();
+            data.extend_from_slice(&secret_for_oracle);
+            ecb_encrypt(data, TEST_KEY)
+        };
+
+        assert_eq!(secret, crack_ecb_oracle(oracle));
+    }
+}
@@ -12,10 +12,14 @@
 //! best, and can sometimes be trivially broken.
 //!
 use aes::{
-    cipher::{generic_array::GenericArray, BlockCipher, BlockDecrypt, BlockEncrypt, KeyInit},
-    Aes128,
+    cipher::{generic_array::GenericArray, BlockDecrypt, BlockEncrypt, KeyInit},
+    Aes128 as Aes128Impl,
 };
 use rand::RngCore;
+use std::collections::HashSet;
+
+pub mod ecb_oracle;
+pub mod padding_oracle;
 
 ///We're using AES 128 which has 16-byte (128 bit) blocks.
 const BLOCK_SIZE: usize = 16;
@@ -27,7 +31,7 @@ fn aes_encrypt(data: [u8; BLOCK_SIZE], key: &[u8; BLOCK_SIZE]) -> [u8; BLOCK_SIZ
     let mut block = GenericArray::from(data);
     let key = GenericArray::from(*key);
 
-    let cipher = Aes128::new(&key);
+    let cipher = Aes128Impl::new(&key);
 
     cipher.encrypt_block(&mut block);
 
@@ -41,13 +45,52 @@ fn aes_decrypt(data: [u8; BLOCK_SIZE], key: &[u8; BLOCK_SIZE]) -> [u8; BLOCK_SIZ
     let mut block = GenericArray::from(data);
     let key = GenericArray::from(*key);
 
-    let cipher = Aes128::new(&key);
+    let cipher = Aes128Impl::new(&key);
 
     cipher.decrypt_block(&mut block);
 
     block.into()
 }
 
+/// Abstraction over a block cipher so that the modes of operation don't have to
+/// hardwire AES-128. A cipher knows how to encrypt and decrypt a single block, so
+/// any primitive with the same 16-byte block (e.g. AES-256) can be dropped into
+/// `ecb_*`, `cbc_*`, and `ctr_*` without touching the mode logic.
+///
+/// The block size is fixed at `BLOCK_SIZE` (16 bytes): `group`, `pad`, and
+/// `un_pad` all work in fixed 16-byte arrays, so ciphers with a different block
+/// width (such as DES) are out of scope here.
+pub trait BlockCipher {
+    /// Encrypts a single block under the cipher's key.
+    fn encrypt_block(&self, block: [u8; BLOCK_SIZE]) -> [u8; BLOCK_SIZE];
+
+    /// Decrypts a single block under the cipher's key.
+    fn decrypt_block(&self, block: [u8; BLOCK_SIZE]) -> [u8; BLOCK_SIZE];
+}
+
+/// AES-128 as a `BlockCipher`. It simply carries the key and defers to the
+/// `aes_encrypt`/`aes_decrypt` helpers for the actual block operations.
+pub struct Aes128 {
+    key: [u8; BLOCK_SIZE],
+}
+
+impl Aes128 {
+    /// Builds an AES-128 cipher from a 16-byte key.
+    pub fn new(key: [u8; BLOCK_SIZE]) -> Self {
+        Self { key }
+    }
+}
+
+impl BlockCipher for Aes128 {
+    fn encrypt_block(&self, block: [u8; BLOCK_SIZE]) -> [u8; BLOCK_SIZE] {
+        aes_encrypt(block, &self.key)
+    }
+
+    fn decrypt_block(&self, block: [u8; BLOCK_SIZE]) -> [u8; BLOCK_SIZE] {
+        aes_decrypt(block, &self.key)
+    }
+}
+
 /// Before we can begin encrypting our raw data, we need it to be a multiple of the
 /// block length which is 16 bytes (128 bits) in AES128.
 ///
@@ -118,6 +161,53 @@ fn un_pad(data: Vec<u8>) -> Vec<u8> {
     data[..data.len() - last_byte].to_vec()
 }
 
+/// Reasons `un_pad_checked` can reject a buffer as not being validly PKCS#7 padded.
+#[derive(Debug, PartialEq, Eq)]
+pub enum PaddingError {
+    /// The data length is zero or not a multiple of the block size.
+    InvalidLength,
+    /// The trailing bytes are not a well-formed PKCS#7 padding sequence.
+    InvalidPadding,
+}
+
+impl core::fmt::Display for PaddingError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PaddingError::InvalidLength => {
+                write!(f, "data length is not a nonzero multiple of the block size")
+            }
+            PaddingError::InvalidPadding => write!(f, "malformed PKCS#7 padding"),
+        }
+    }
+}
+
+impl std::error::Error for PaddingError {}
+
+/// Like `un_pad`, but fully validates the PKCS#7 padding instead of silently
+/// returning the input on corruption. The buffer must be a nonzero multiple of
+/// `BLOCK_SIZE`, the final byte `n` must be in `1..=BLOCK_SIZE`, and all of the
+/// final `n` bytes must equal `n`. Any deviation yields an `Err`, which is what
+/// makes correct decryption error reporting — and the padding-oracle work — possible.
+pub fn un_pad_checked(data: Vec<u8>) -> Result<Vec<u8>, PaddingError> {
+    if data.is_empty() || !data.len().is_multiple_of(BLOCK_SIZE) {
+        return Err(PaddingError::InvalidLength);
+    }
+
+    let number_pad_bytes = *data.last().unwrap() as usize;
+    if number_pad_bytes == 0 || number_pad_bytes > BLOCK_SIZE {
+        return Err(PaddingError::InvalidPadding);
+    }
+
+    if data[data.len() - number_pad_bytes..]
+        .iter()
+        .any(|&byte| byte as usize != number_pad_bytes)
+    {
+        return Err(PaddingError::InvalidPadding);
+    }
+
+    Ok(data[..data.len() - number_pad_bytes].to_vec())
+}
+
 /// The first mode we will implement is the Electronic Code Book, or ECB mode.
 /// Warning: THIS MODE IS NOT SECURE!!!!
 ///
@@ -126,6 +216,11 @@ fn un_pad(data: Vec<u8>) -> Vec<u8> {
 /// One good thing about this mode is that it is parallelizable. But to see why it is
 /// insecure look at: https://www.ubiqsecurity.com/wp-content/uploads/2022/02/ECB2.png
 pub fn ecb_encrypt(plain_text: Vec<u8>, key: [u8; 16]) -> Vec<u8> {
+    ecb_encrypt_with(&Aes128::new(key), plain_text)
+}
+
+/// Generic ECB encryption over any `BlockCipher`.
+pub fn ecb_encrypt_with<C: BlockCipher>(cipher: &C, plain_text: Vec<u8>) -> Vec<u8> {
     // Pad the data to the correct length
     let padded_data: Vec<u8> = pad(plain_text);
 
@@ -135,7 +230,7 @@ pub fn ecb_encrypt(plain_text: Vec<u8>, key: [u8; 16]) -> Vec<u8> {
     // Encrypt each block
     let encrypted_blocks: Vec<[u8; BLOCK_SIZE]> = blocks
         .iter()
-        .map(|block| aes_encrypt(*block, &key))
+        .map(|block| cipher.encrypt_block(*block))
         .collect();
 
     // Ungroup the blocks
@@ -144,19 +239,71 @@ pub fn ecb_encrypt(plain_text: Vec<u8>, key: [u8; 16]) -> Vec<u8> {
 
 /// Opposite of ecb_encrypt.
 pub fn ecb_decrypt(cipher_text: Vec<u8>, key: [u8; BLOCK_SIZE]) -> Vec<u8> {
+    ecb_decrypt_with(&Aes128::new(key), cipher_text)
+}
+
+/// Generic ECB decryption over any `BlockCipher`.
+pub fn ecb_decrypt_with<C: BlockCipher>(cipher: &C, cipher_text: Vec<u8>) -> Vec<u8> {
     // Group the data into blocks
     let blocks = group(cipher_text);
 
     // Decrypt each block
     let decrypted_blocks: Vec<[u8; BLOCK_SIZE]> = blocks
         .iter()
-        .map(|block| aes_decrypt(*block, &key))
+        .map(|block| cipher.decrypt_block(*block))
         .collect();
 
     // Ungroup the blocks and unpad the data
     un_pad(un_group(decrypted_blocks))
 }
 
+/// Like `ecb_decrypt`, but validates the PKCS#7 padding and reports a
+/// `PaddingError` instead of silently returning possibly-corrupt data.
+pub fn ecb_decrypt_checked(
+    cipher_text: Vec<u8>,
+    key: [u8; BLOCK_SIZE],
+) -> Result<Vec<u8>, PaddingError> {
+    let cipher = Aes128::new(key);
+    let blocks = group(cipher_text);
+
+    let decrypted_blocks: Vec<[u8; BLOCK_SIZE]> = blocks
+        .iter()
+        .map(|block| cipher.decrypt_block(*block))
+        .collect();
+
+    un_pad_checked(un_group(decrypted_blocks))
+}
+
+/// Counts how many blocks of a ciphertext are byte-for-byte repeats of an
+/// earlier block. The data is sliced into `BLOCK_SIZE` blocks (any trailing
+/// partial block is ignored) and each block is checked against the set of blocks
+/// seen so far; every block that has already appeared adds one to the count.
+pub fn count_duplicate_blocks(cipher_text: &[u8]) -> usize {
+    let whole_blocks = cipher_text.len() - cipher_text.len() % BLOCK_SIZE;
+    let blocks = group(cipher_text[..whole_blocks].to_vec());
+
+    let mut seen: HashSet<[u8; BLOCK_SIZE]> = HashSet::new();
+    let mut duplicates = 0;
+    for block in blocks {
+        if !seen.insert(block) {
+            duplicates += 1;
+        }
+    }
+
+    duplicates
+}
+
+/// Flags a ciphertext as likely ECB output by looking for repeated blocks.
+///
+/// Because ECB maps identical plaintext blocks to identical ciphertext blocks,
+/// any duplicate block is a strong signal of ECB. This returns `true` as soon as
+/// `count_duplicate_blocks` finds at least one repeat, which is enough to build an
+/// oracle classifier that tells `ecb_encrypt` output apart from `cbc_encrypt` or
+/// `ctr_encrypt` output on structured plaintext.
+pub fn detect_ecb(cipher_text: &[u8]) -> bool {
+    count_duplicate_blocks(cipher_text) > 0
+}
+
 /// XORs two blocks of data.
 fn xor_block(block1: [u8; BLOCK_SIZE], block2: [u8; BLOCK_SIZE]) -> [u8; BLOCK_SIZE] {
     let mut result: [u8; BLOCK_SIZE] = Default::default();
@@ -179,6 +326,11 @@ fn xor_block(block1: [u8; BLOCK_SIZE], block2: [u8; BLOCK_SIZE]) -> [u8; BLOCK_S
 /// very first block because it doesn't have a previous block. Typically this IV
 /// is inserted as the first block of ciphertext.
 pub fn cbc_encrypt(plain_text: Vec<u8>, key: [u8; BLOCK_SIZE]) -> Vec<u8> {
+    cbc_encrypt_with(&Aes128::new(key), plain_text)
+}
+
+/// Generic CBC encryption over any `BlockCipher`.
+pub fn cbc_encrypt_with<C: BlockCipher>(cipher: &C, plain_text: Vec<u8>) -> Vec<u8> {
     // Remember to generate a random initialization vector for the first block.
     let mut rng = rand::thread_rng();
     let mut iv: [u8; BLOCK_SIZE] = Default::default();
@@ -194,7 +346,7 @@ pub fn cbc_encrypt(plain_text: Vec<u8>, key: [u8; BLOCK_SIZE]) -> Vec<u8> {
 
     for block in blocks {
         let xored_block: [u8; BLOCK_SIZE] = xor_block(block, previous_block);
-        let encrypted_block = aes_encrypt(xored_block, &key);
+        let encrypted_block = cipher.encrypt_block(xored_block);
         encrypted_blocks.push(encrypted_block);
         previous_block = encrypted_block;
     }
@@ -204,13 +356,18 @@ pub fn cbc_encrypt(plain_text: Vec<u8>, key: [u8; BLOCK_SIZE]) -> Vec<u8> {
 }
 
 pub fn cbc_decrypt(cipher_text: Vec<u8>, key: [u8; BLOCK_SIZE]) -> Vec<u8> {
+    cbc_decrypt_with(&Aes128::new(key), cipher_text)
+}
+
+/// Generic CBC decryption over any `BlockCipher`.
+pub fn cbc_decrypt_with<C: BlockCipher>(cipher: &C, cipher_text: Vec<u8>) -> Vec<u8> {
     let blocks = group(cipher_text);
 
     let mut previous_block = blocks[0];
     let mut decrypted_blocks = Vec::new();
 
     for block in blocks.iter().skip(1) {
-        let decrypted_block = aes_decrypt(*block, &key);
+        let decrypted_block = cipher.decrypt_block(*block);
         let xored_block = xor_block(decrypted_block, previous_block);
         decrypted_blocks.push(xored_block);
         previous_block = *block;
@@ -220,6 +377,28 @@ pub fn cbc_decrypt(cipher_text: Vec<u8>, key: [u8; BLOCK_SIZE]) -> Vec<u8> {
     un_pad(decrypted_groups)
 }
 
+/// Like `cbc_decrypt`, but validates the PKCS#7 padding and reports a
+/// `PaddingError` instead of silently returning possibly-corrupt data.
+pub fn cbc_decrypt_checked(
+    cipher_text: Vec<u8>,
+    key: [u8; BLOCK_SIZE],
+) -> Result<Vec<u8>, PaddingError> {
+    let cipher = Aes128::new(key);
+    let blocks = group(cipher_text);
+
+    let mut previous_block = blocks[0];
+    let mut decrypted_blocks = Vec::new();
+
+    for block in blocks.iter().skip(1) {
+        let decrypted_block = cipher.decrypt_block(*block);
+        let xored_block = xor_block(decrypted_block, previous_block);
+        decrypted_blocks.push(xored_block);
+        previous_block = *block;
+    }
+
+    un_pad_checked(un_group(decrypted_blocks))
+}
+
 /// Another mode which you can implement on your own is counter mode.
 /// This mode is secure as well, and is used in real world applications.
 /// It allows parallelized encryption and decryption, as well as random read access when decrypting.
@@ -237,6 +416,11 @@ pub fn cbc_decrypt(cipher_text: Vec<u8>, key: [u8; BLOCK_SIZE]) -> Vec<u8> {
 /// Once again, you will need to generate a random nonce which is 64 bits long. This should be
 /// inserted as the first block of the ciphertext.
 pub fn ctr_encrypt(plain_text: Vec<u8>, key: [u8; BLOCK_SIZE]) -> Vec<u8> {
+    ctr_encrypt_with(&Aes128::new(key), plain_text)
+}
+
+/// Generic CTR encryption over any `BlockCipher`.
+pub fn ctr_encrypt_with<C: BlockCipher>(cipher: &C, plain_text: Vec<u8>) -> Vec<u8> {
     let mut rng = rand::thread_rng();
     let mut nonce: [u8; BLOCK_SIZE / 2] = [0; BLOCK_SIZE / 2];
     rng.fill_bytes(&mut nonce);
@@ -256,7 +440,7 @@ pub fn ctr_encrypt(plain_text: Vec<u8>, key: [u8; BLOCK_SIZE]) -> Vec<u8> {
         let mut counter_block: [u8; BLOCK_SIZE] = [0; BLOCK_SIZE];
         counter_block[..BLOCK_SIZE / 2].copy_from_slice(&nonce);
         counter_block[BLOCK_SIZE / 2..].copy_from_slice(&counter.to_be_bytes());
-        let encrypted_counter_block = aes_encrypt(counter_block, &key);
+        let encrypted_counter_block = cipher.encrypt_block(counter_block);
         let xored_block = xor_block(block, encrypted_counter_block);
         encrypted_blocks.push(xored_block);
         counter += 1;
@@ -266,6 +450,11 @@ pub fn ctr_encrypt(plain_text: Vec<u8>, key: [u8; BLOCK_SIZE]) -> Vec<u8> {
 }
 
 pub fn ctr_decrypt(cipher_text: Vec<u8>, key: [u8; BLOCK_SIZE]) -> Vec<u8> {
+    ctr_decrypt_with(&Aes128::new(key), cipher_text)
+}
+
+/// Generic CTR decryption over any `BlockCipher`.
+pub fn ctr_decrypt_with<C: BlockCipher>(cipher: &C, cipher_text: Vec<u8>) -> Vec<u8> {
     let blocks = group(cipher_text);
     let nonce = &blocks[0][..BLOCK_SIZE / 2];
     let mut counter: u64 = 0;
@@ -275,7 +464,7 @@ pub fn ctr_decrypt(cipher_text: Vec<u8>, key: [u8; BLOCK_SIZE]) -> Vec<u8> {
         let mut counter_block: [u8; BLOCK_SIZE] = [0; BLOCK_SIZE];
         counter_block[..BLOCK_SIZE / 2].copy_from_slice(&nonce);
         counter_block[BLOCK_SIZE / 2..].copy_from_slice(&counter.to_be_bytes());
-        let encrypted_counter_block = aes_encrypt(counter_block, &key);
+        let encrypted_counter_block = cipher.encrypt_block(counter_block);
         let decrypted_block = xor_block(*block, encrypted_counter_block);
         decrypted_blocks.push(decrypted_block);
         counter += 1;
@@ -285,6 +474,101 @@ pub fn ctr_decrypt(cipher_text: Vec<u8>, key: [u8; BLOCK_SIZE]) -> Vec<u8> {
     un_pad(decrypted_data)
 }
 
+/// Cipher Feedback (CFB) mode turns the block cipher into a self-synchronizing
+/// stream cipher. Unlike ECB/CBC, we never call `aes_decrypt`: the cipher is only
+/// ever used to generate a keystream block that is XORed with the data.
+///
+/// A random IV is generated and prepended as the first ciphertext block. The
+/// keystream for block 0 is `aes_encrypt(IV)`, and for every subsequent block it
+/// is `aes_encrypt` of the *previous ciphertext block*. Because the data is XORed
+/// with a keystream, no padding is needed; a trailing partial block is handled by
+/// truncating the keystream XOR to the plaintext length.
+pub fn cfb_encrypt(plain_text: Vec<u8>, key: [u8; BLOCK_SIZE]) -> Vec<u8> {
+    let mut rng = rand::thread_rng();
+    let mut iv: [u8; BLOCK_SIZE] = Default::default();
+    rng.fill_bytes(&mut iv);
+
+    let mut cipher_text = Vec::with_capacity(plain_text.len() + BLOCK_SIZE);
+    cipher_text.extend_from_slice(&iv);
+
+    let mut feedback = iv;
+    for chunk in plain_text.chunks(BLOCK_SIZE) {
+        let keystream = aes_encrypt(feedback, &key);
+        let mut cipher_block: [u8; BLOCK_SIZE] = Default::default();
+        for i in 0..chunk.len() {
+            cipher_block[i] = chunk[i] ^ keystream[i];
+        }
+        cipher_text.extend_from_slice(&cipher_block[..chunk.len()]);
+        feedback = cipher_block;
+    }
+
+    cipher_text
+}
+
+/// Opposite of cfb_encrypt. Note that the keystream is still produced with
+/// `aes_encrypt`, exactly as during encryption; only the XOR direction changes.
+pub fn cfb_decrypt(cipher_text: Vec<u8>, key: [u8; BLOCK_SIZE]) -> Vec<u8> {
+    let mut iv: [u8; BLOCK_SIZE] = Default::default();
+    iv.copy_from_slice(&cipher_text[..BLOCK_SIZE]);
+
+    let mut plain_text = Vec::with_capacity(cipher_text.len() - BLOCK_SIZE);
+
+    let mut feedback = iv;
+    for chunk in cipher_text[BLOCK_SIZE..].chunks(BLOCK_SIZE) {
+        let keystream = aes_encrypt(feedback, &key);
+        for i in 0..chunk.len() {
+            plain_text.push(chunk[i] ^ keystream[i]);
+        }
+        let mut cipher_block: [u8; BLOCK_SIZE] = Default::default();
+        cipher_block[..chunk.len()].copy_from_slice(chunk);
+        feedback = cipher_block;
+    }
+
+    plain_text
+}
+
+/// Output Feedback (OFB) mode is identical to CFB except that the feedback chain
+/// is the raw keystream output rather than the ciphertext. The keystream is
+/// `S_0 = aes_encrypt(IV)`, `S_i = aes_encrypt(S_{i-1})`, and each block of data is
+/// `C_i = P_i XOR S_i`. Because the keystream is independent of the data, the same
+/// keystream XOR both encrypts and decrypts. A trailing partial block is truncated.
+pub fn ofb_encrypt(plain_text: Vec<u8>, key: [u8; BLOCK_SIZE]) -> Vec<u8> {
+    let mut rng = rand::thread_rng();
+    let mut iv: [u8; BLOCK_SIZE] = Default::default();
+    rng.fill_bytes(&mut iv);
+
+    let mut cipher_text = Vec::with_capacity(plain_text.len() + BLOCK_SIZE);
+    cipher_text.extend_from_slice(&iv);
+
+    let mut keystream = iv;
+    for chunk in plain_text.chunks(BLOCK_SIZE) {
+        keystream = aes_encrypt(keystream, &key);
+        for i in 0..chunk.len() {
+            cipher_text.push(chunk[i] ^ keystream[i]);
+        }
+    }
+
+    cipher_text
+}
+
+/// Opposite of ofb_encrypt. Since the keystream only depends on the IV and the
+/// key, decryption regenerates the exact same keystream and XORs it back out.
+pub fn ofb_decrypt(cipher_text: Vec<u8>, key: [u8; BLOCK_SIZE]) -> Vec<u8> {
+    let mut keystream: [u8; BLOCK_SIZE] = Default::default();
+    keystream.copy_from_slice(&cipher_text[..BLOCK_SIZE]);
+
+    let mut plain_text = Vec::with_capacity(cipher_text.len() - BLOCK_SIZE);
+
+    for chunk in cipher_text[BLOCK_SIZE..].chunks(BLOCK_SIZE) {
+        keystream = aes_encrypt(keystream, &key);
+        for i in 0..chunk.len() {
+            plain_text.push(chunk[i] ^ keystream[i]);
+        }
+    }
+
+    plain_text
+}
+
 
 
 #[cfg(test)]
@@ -364,4 +648,76 @@ mod tests {
         let decrypted_bad = ctr_decrypt(modified_ciphertext, TEST_KEY);
         assert_ne!(plaintext, decrypted_bad);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn un_pad_checked_test() {
+        // Valid padding of both kinds round-trips and strips correctly.
+        let data: Vec<u8> = (0..53).collect();
+        assert_eq!(Ok(data.clone()), un_pad_checked(pad(data)));
+
+        let data: Vec<u8> = (0..48).collect();
+        assert_eq!(Ok(data.clone()), un_pad_checked(pad(data)));
+
+        // A length that isn't a nonzero multiple of the block size is rejected.
+        assert_eq!(Err(PaddingError::InvalidLength), un_pad_checked(vec![1, 2, 3]));
+        assert_eq!(Err(PaddingError::InvalidLength), un_pad_checked(vec![]));
+
+        // A final byte that doesn't match the trailing bytes is rejected.
+        let mut bad = vec![0u8; BLOCK_SIZE];
+        bad[BLOCK_SIZE - 1] = 4; // claims 4 pad bytes, but they aren't all 4
+        assert_eq!(Err(PaddingError::InvalidPadding), un_pad_checked(bad));
+
+        // A zero final byte is never valid PKCS#7.
+        assert_eq!(
+            Err(PaddingError::InvalidPadding),
+            un_pad_checked(vec![0u8; BLOCK_SIZE])
+        );
+    }
+
+    #[test]
+    fn cbc_decrypt_checked_test() {
+        let plaintext = b"Polkadot Blockchain Academy!".to_vec();
+        let ciphertext = cbc_encrypt(plaintext.clone(), TEST_KEY);
+        assert_eq!(Ok(plaintext), cbc_decrypt_checked(ciphertext, TEST_KEY));
+    }
+
+    #[test]
+    fn detect_ecb_test() {
+        // Two identical plaintext blocks become two identical ciphertext blocks
+        // under ECB, so detection fires. CBC and CTR randomize them, so it doesn't.
+        let plaintext = vec![42u8; 2 * BLOCK_SIZE];
+        assert!(detect_ecb(&ecb_encrypt(plaintext.clone(), TEST_KEY)));
+        assert!(!detect_ecb(&cbc_encrypt(plaintext.clone(), TEST_KEY)));
+        assert!(!detect_ecb(&ctr_encrypt(plaintext, TEST_KEY)));
+    }
+
+    #[test]
+    fn cfb_roundtrip_test() {
+        // CFB feeds the previous ciphertext block back through the cipher, so only
+        // a full encrypt/decrypt round trip exercises that feedback chain.
+        let plaintext = b"Polkadot Blockchain Academy!".to_vec();
+        let ciphertext = cfb_encrypt(plaintext.clone(), TEST_KEY);
+        let decrypted = cfb_decrypt(ciphertext.clone(), TEST_KEY);
+        assert_eq!(plaintext.clone(), decrypted);
+
+        let mut modified_ciphertext = ciphertext.clone();
+        modified_ciphertext[18] = 0;
+        let decrypted_bad = cfb_decrypt(modified_ciphertext, TEST_KEY);
+        assert_ne!(plaintext, decrypted_bad);
+    }
+
+    #[test]
+    fn ofb_roundtrip_test() {
+        // OFB's keystream depends only on the IV and the key, so decryption XORs out
+        // the same keystream; the round trip checks that it regenerates identically.
+        let plaintext = b"Polkadot Blockchain Academy!".to_vec();
+        let ciphertext = ofb_encrypt(plaintext.clone(), TEST_KEY);
+        let decrypted = ofb_decrypt(ciphertext.clone(), TEST_KEY);
+        assert_eq!(plaintext.clone(), decrypted);
+
+        let mut modified_ciphertext = ciphertext.clone();
+        modified_ciphertext[18] = 0;
+        let decrypted_bad = ofb_decrypt(modified_ciphertext, TEST_KEY);
+        assert_ne!(plaintext, decrypted_bad);
+    }
+}
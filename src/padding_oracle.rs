@@ -0,0 +1,131 @@
+//! A demonstration of the classic CBC padding-oracle attack.
+//!
+//! The attacker never sees the key and never calls the cipher. All they have is
+//! an `oracle` closure that, given a ciphertext, reveals a single bit: whether
+//! decryption produced valid PKCS#7 padding. This is exactly the distinction that
+//! `un_pad_checked` (unlike the silent `un_pad`) makes observable, which is why
+//! erroring on bad padding is a genuine security concern and not just tidiness.
+//!
+//! The ciphertext is laid out the same way `cbc_encrypt` produces it: the first
+//! block is the IV, followed by the real ciphertext blocks.
+
+use crate::BLOCK_SIZE;
+
+/// Recovers the full plaintext of a CBC ciphertext using only a padding oracle.
+///
+/// `cipher_text` must include the IV as its first block (as `cbc_encrypt`
+/// produces). The oracle is treated like `cbc_decrypt_checked`: its first block
+/// is the IV and it returns `true` iff the final block decrypts to valid padding.
+pub fn recover_plaintext<O: Fn(&[u8]) -> bool>(cipher_text: &[u8], oracle: O) -> Vec<u8> {
+    let blocks: Vec<[u8; BLOCK_SIZE]> = cipher_text
+        .chunks_exact(BLOCK_SIZE)
+        .map(|chunk| {
+            let mut block = [0u8; BLOCK_SIZE];
+            block.copy_from_slice(chunk);
+            block
+        })
+        .collect();
+
+    let mut plaintext = Vec::with_capacity(cipher_text.len() - BLOCK_SIZE);
+
+    // Block 0 is the IV, so every subsequent block is recovered against the block
+    // that immediately precedes it in the real ciphertext.
+    for i in 1..blocks.len() {
+        let recovered = recover_block(&blocks[i - 1], &blocks[i], &oracle);
+        plaintext.extend_from_slice(&recovered);
+    }
+
+    crate::un_pad(plaintext)
+}
+
+/// Recovers a single plaintext block given the real preceding ciphertext block
+/// and the target block, using the oracle one byte at a time from right to left.
+fn recover_block<O: Fn(&[u8]) -> bool>(
+    prev: &[u8; BLOCK_SIZE],
+    target: &[u8; BLOCK_SIZE],
+    oracle: &O,
+) -> [u8; BLOCK_SIZE] {
+    // `intermediate[j]` is the decryption of `target` before the IV XOR, i.e.
+    // `aes_decrypt(target)[j]`. Once known, the plaintext byte is just
+    // `intermediate[j] XOR prev[j]`.
+    let mut intermediate = [0u8; BLOCK_SIZE];
+    let mut plaintext_block = [0u8; BLOCK_SIZE];
+
+    for p in 1..=BLOCK_SIZE {
+        let pad = p as u8;
+        let target_idx = BLOCK_SIZE - p;
+
+        // Craft a forged IV `c_prime`. The already-known tail bytes are set so
+        // they decrypt to the current padding value `p`; the target byte is what
+        // we brute-force.
+        let mut c_prime = [0u8; BLOCK_SIZE];
+        for known in (target_idx + 1)..BLOCK_SIZE {
+            c_prime[known] = intermediate[known] ^ pad;
+        }
+
+        for guess in 0u16..=255 {
+            c_prime[target_idx] = guess as u8;
+
+            let mut probe = Vec::with_capacity(2 * BLOCK_SIZE);
+            probe.extend_from_slice(&c_prime);
+            probe.extend_from_slice(target);
+
+            if oracle(&probe) && confirm(&mut c_prime, target, target_idx, &oracle) {
+                intermediate[target_idx] = c_prime[target_idx] ^ pad;
+                plaintext_block[target_idx] = intermediate[target_idx] ^ prev[target_idx];
+                break;
+            }
+        }
+    }
+
+    plaintext_block
+}
+
+/// Guards against a false positive when recovering the very last byte: a crafted
+/// byte can accidentally yield valid padding (e.g. the plaintext already ends in
+/// `0x02 0x02`). Perturbing the neighbouring byte and re-querying confirms that
+/// the accepted padding really is a single `0x01`. For every other position the
+/// tail is pinned, so there is nothing to disambiguate.
+fn confirm<O: Fn(&[u8]) -> bool>(
+    c_prime: &mut [u8; BLOCK_SIZE],
+    target: &[u8; BLOCK_SIZE],
+    target_idx: usize,
+    oracle: &O,
+) -> bool {
+    if target_idx == 0 {
+        return true;
+    }
+
+    let saved = c_prime[target_idx - 1];
+    c_prime[target_idx - 1] ^= 0xff;
+
+    let mut probe = Vec::with_capacity(2 * BLOCK_SIZE);
+    probe.extend_from_slice(c_prime);
+    probe.extend_from_slice(target);
+    let still_valid = oracle(&probe);
+
+    c_prime[target_idx - 1] = saved;
+    still_valid
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{cbc_decrypt_checked, cbc_encrypt};
+
+    const TEST_KEY: [u8; 16] = [
+        6, 108, 74, 203, 170, 212, 94, 238, 171, 104, 19, 17, 248, 197, 127, 138,
+    ];
+
+    #[test]
+    fn recovers_plaintext_via_oracle() {
+        let plaintext = b"Polkadot Blockchain Academy!".to_vec();
+        let ciphertext = cbc_encrypt(plaintext.clone(), TEST_KEY);
+
+        // The only capability leaked to the attacker: does this decrypt cleanly?
+        let oracle = |candidate: &[u8]| cbc_decrypt_checked(candidate.to_vec(), TEST_KEY).is_ok();
+
+        let recovered = recover_plaintext(&ciphertext, oracle);
+        assert_eq!(plaintext, recovered);
+    }
+}